@@ -15,6 +15,19 @@ pub fn component_derive(inp: TokenStream) -> TokenStream {
     let generics = &input.generics;
     let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
 
+    // `read_component`/`write_component` require `T: Pod` (see
+    // `ecs::archetype::Archetype`), which in turn requires `T: Copy`. Only
+    // emit the `Pod` impls when the struct itself also derives `Copy`, so
+    // structs that hold non-`Copy` leaves (and so can never be `Pod`) don't
+    // get a `Pod` impl they can't satisfy.
+    let derives_copy = input.attrs.iter().any(|attr| {
+        attr.path().is_ident("derive")
+            && attr
+                .parse_args_with(syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+                .map(|paths| paths.iter().any(|path| path.is_ident("Copy")))
+                .unwrap_or(false)
+    });
+
     let (types, output_types, dismember_body) = match &input.data {
         Data::Struct(data) => match &data.fields {
             syn::Fields::Named(f) => {
@@ -56,12 +69,35 @@ pub fn component_derive(inp: TokenStream) -> TokenStream {
         _ => unimplemented!(),
     };
 
+    let pod_impl = if derives_copy {
+        quote! {
+            // Safety: `#struct_name` derives `Copy`, so every field is itself
+            // `Copy` (no owned heap leaves). Freedom from padding rests on
+            // the struct being `#[repr(packed)]` (or fieldless); this derive
+            // does not check the struct's `repr`.
+            unsafe impl #generics lynx_traits::Zeroable for #struct_name #generics {}
+            unsafe impl #generics lynx_traits::AnyBitPattern for #struct_name #generics {}
+            unsafe impl #generics lynx_traits::Pod for #struct_name #generics {}
+        }
+    } else {
+        quote! {}
+    };
+
     let derive = quote! {
         use lynx_traits::*;
 
         impl #generics Component for #struct_name #generics {
             type DismemberedOutput = #output_types;
             const COUNT: usize = 0 #(+ <#types as Component>::COUNT)*;
+            const SIZES: [usize; Self::COUNT] = lynx_traits::concat_sizes(&[
+                #( &(<#types as Component>::SIZES) as &[usize] ),*
+            ]);
+            const ALIGNS: [usize; Self::COUNT] = lynx_traits::concat_sizes(&[
+                #( &(<#types as Component>::ALIGNS) as &[usize] ),*
+            ]);
+            const FIELD_LAYOUTS: [lynx_traits::FieldLayout; Self::COUNT] =
+                lynx_traits::compute_field_layouts(&Self::SIZES, &Self::ALIGNS);
+            const ID: u32 = #id;
 
             fn dismember(self) -> Self::DismemberedOutput {
                 #dismember_body
@@ -70,26 +106,21 @@ pub fn component_derive(inp: TokenStream) -> TokenStream {
             fn dismembered_type_count() -> u32 {
                 Self::COUNT as u32
             }
-            fn sizes() -> &'static [usize] {
-                static SIZES: std::sync::OnceLock<&'static [usize]> = std::sync::OnceLock::new();
-                SIZES.get_or_init(|| {
-                    let computed: Vec<usize> = match std::mem::size_of::<Self>() {
-                        0 => vec![0 as usize],
-                        _ => {
-                            let slices: Vec<&'static [usize]> = vec![
-                                #( <#types as Component>::sizes() ),*
-                            ];
-
-                            slices.into_iter().flatten().copied().collect()
-                        }
-                    };
+
+            fn drop_fns() -> &'static [Option<unsafe fn(*mut u8)>] {
+                static DROP_FNS: std::sync::OnceLock<&'static [Option<unsafe fn(*mut u8)>]> = std::sync::OnceLock::new();
+                DROP_FNS.get_or_init(|| {
+                    let slices: Vec<&'static [Option<unsafe fn(*mut u8)>]> = vec![
+                        #( <#types as Component>::drop_fns() ),*
+                    ];
+                    let computed: Vec<Option<unsafe fn(*mut u8)>> =
+                        slices.into_iter().flatten().copied().collect();
                     Box::leak(computed.into_boxed_slice())
                 })
             }
-            fn id() -> u32{
-                #id
-            }
         }
+
+        #pod_impl
     };
 
     TokenStream::from(derive)
@@ -114,13 +145,12 @@ pub fn derive_signature(input: TokenStream) -> TokenStream {
         #(archetype.insert_component::<#types>(&self.#fields).unwrap();)*
         archetype.set_entity_count(archetype.get_entity_count() + 1);
         if archetype.column_must_resize() {
-            let entity_count = archetype.get_entity_count().clone();
             #(
                 let sizes = <#types as Component>::sizes();
                 for (index, value) in sizes.iter().enumerate() {
-                    archetype.get_mut::<#types>(index).unwrap()
-                                                      .resize_bytes(entity_count as usize * value,
-                                                                    (entity_count as usize * value) * 2);
+                    let column = archetype.get_mut::<#types>(index).unwrap();
+                    let cap = column.capacity();
+                    column.reserve_bytes(cap, *value);
                 }
             )*
         }
@@ -130,8 +160,57 @@ pub fn derive_signature(input: TokenStream) -> TokenStream {
         #(archetype.initialize_column::<#types>();)*
     };
 
+    let bulk = quote! {
+        let start = archetype.get_entity_count();
+        #(
+            let sizes = <#types as Component>::sizes();
+            for (index, value) in sizes.iter().enumerate() {
+                let column = archetype.get_mut::<#types>(index).unwrap();
+                let cap = column.capacity();
+                let required = (start + times) * value;
+                if required > cap {
+                    column.grow_amortized_bytes(cap, required);
+                }
+            }
+        )*
+        #(
+            {
+                let sizes = <#types as Component>::sizes();
+                let mut last_index = 0;
+                for (index, value) in sizes.iter().enumerate() {
+                    let ptr = core::ptr::addr_of!(self.#fields) as *const u8;
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr.add(last_index), *value) };
+                    let column = archetype.get_mut::<#types>(index).unwrap();
+                    for i in 0..times {
+                        column.write_bytes(start + i, bytes);
+                    }
+                    last_index += *value;
+                }
+            }
+        )*
+        archetype.set_entity_count(start + times);
+    };
+
     let output = quote! {
         impl #generics Signature for #ident #generics {
+            const COUNT: usize = 0 #(+ <#types as Component>::COUNT)*;
+            const IDS: [u32; Self::COUNT] = lynx_traits::concat_component_ids(&[
+                #( (<#types as Component>::ID, <#types as Component>::COUNT) ),*
+            ]);
+
+            /// Built from each component's runtime `Component::id` (see
+            /// `lynx_traits::concat_component_ids_dyn`), not `Self::IDS`, so
+            /// raw `Copy` fields resolve through the registry instead of the
+            /// `0` their compile-time `Component::ID` shares.
+            fn gen_ids() -> &'static [u32] {
+                static IDS: std::sync::OnceLock<Vec<u32>> = std::sync::OnceLock::new();
+                IDS.get_or_init(|| {
+                    lynx_traits::concat_component_ids_dyn(&[
+                        #( (<#types as Component>::id(), <#types as Component>::COUNT) ),*
+                    ])
+                })
+            }
+
             #[inline(always)]
             fn insert_components(&self, archetype: &mut impl Archetype) {
                 #insert
@@ -143,22 +222,19 @@ pub fn derive_signature(input: TokenStream) -> TokenStream {
                 }
             }
 
-            fn gen_ids() -> &'static [u32] {
-                static IDS: std::sync::OnceLock<&'static [u32]> = std::sync::OnceLock::new();
-                IDS.get_or_init(|| {
-                    let mut ids = Vec::new();
-                    #(
-                        ids.push(<#types as Component>::id());
-                        for i in 0..<#types as Component>::COUNT - 1 {
-                            ids.push(0);
-                        }
-                    )*
-                    Box::leak(ids.into_boxed_slice())
+            fn bulk(&self, archetype: &mut impl Archetype, times: usize) {
+                #bulk
+            }
+
+            fn read_row(archetype: &impl Archetype, row: usize) -> Result<Self, ArchetypeError> {
+                Ok(Self {
+                    #( #fields: archetype.read_component::<#types>(row)?, )*
                 })
             }
 
-            fn bulk(&self, archetype: &mut impl Archetype, times: usize) {
-                todo!()
+            fn write_row(&self, archetype: &mut impl Archetype, row: usize) -> Result<(), ArchetypeError> {
+                #( archetype.write_component::<#types>(row, &self.#fields)?; )*
+                Ok(())
             }
         }
     };
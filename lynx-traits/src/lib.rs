@@ -1,7 +1,224 @@
 use std::{any::TypeId, collections::HashMap};
-use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, OnceLock};
 use tabled::Tabled;
 
+mod pod;
+pub use pod::*;
+
+/// Concatenates the `SIZES` arrays of a `Component`'s leaf types into one
+/// fixed-size array, in a `const fn` so the derive can compute
+/// `Component::SIZES` at compile time instead of building a `Vec` and
+/// leaking it on first use.
+pub const fn concat_sizes<const N: usize>(parts: &[&[usize]]) -> [usize; N] {
+    let mut out = [0usize; N];
+    let mut out_idx = 0;
+    let mut part_idx = 0;
+    while part_idx < parts.len() {
+        let part = parts[part_idx];
+        let mut i = 0;
+        while i < part.len() {
+            out[out_idx] = part[i];
+            out_idx += 1;
+            i += 1;
+        }
+        part_idx += 1;
+    }
+    out
+}
+
+/// Builds a `Signature`'s `IDS` array at compile time: for each `(id, count)`
+/// leaf component, writes `id` followed by `count - 1` zero padding slots, so
+/// the array stays aligned with the flattened `SIZES`/column layout.
+///
+/// `id` here is each component's compile-time [`Component::ID`], which the
+/// blanket `Copy` impl below can't give a distinct value per type (see
+/// [`registry_id`]) — so `IDS` is only collision-free for signatures built
+/// entirely out of `#[derive(Component)]` types. [`Signature::gen_ids`],
+/// built by [`concat_component_ids_dyn`], is what callers should actually
+/// look components up through.
+pub const fn concat_component_ids<const N: usize>(parts: &[(u32, usize)]) -> [u32; N] {
+    let mut out = [0u32; N];
+    let mut out_idx = 0;
+    let mut part_idx = 0;
+    while part_idx < parts.len() {
+        let (id, count) = parts[part_idx];
+        out[out_idx] = id;
+        out_idx += 1;
+        let mut pad = 1;
+        while pad < count {
+            out[out_idx] = 0;
+            out_idx += 1;
+            pad += 1;
+        }
+        part_idx += 1;
+    }
+    out
+}
+
+/// Runtime counterpart to [`concat_component_ids`], used to build a
+/// `Signature`'s [`Signature::gen_ids`] from each leaf component's *runtime*
+/// [`Component::id()`] instead of its compile-time `ID` constant.
+///
+/// # Purpose
+/// `Component::ID` is a single `const`, so the blanket `Copy` impl (shared by
+/// every raw `Copy` type) can only ever bake `0` into it — every such type's
+/// `IDS` slot collides. `Component::id()` resolves this through the
+/// process-wide registry instead, but that's only a runtime value, so a
+/// `Signature`'s id list built from it can't be a `const` either; callers
+/// memoize the result of this function behind a `OnceLock` instead.
+pub fn concat_component_ids_dyn(parts: &[(u32, usize)]) -> Vec<u32> {
+    let mut out = Vec::new();
+    for &(id, count) in parts {
+        out.push(id);
+        for _ in 1..count {
+            out.push(0);
+        }
+    }
+    out
+}
+
+/// Per-dismembered-field placement within a [`Component`]'s natural (i.e.
+/// non-`#[repr(packed)]`) layout, computed by [`compute_field_layouts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub offset: usize,
+    pub size: usize,
+    pub align: usize,
+}
+
+/// Lays out `N` dismembered leaf fields the way rustc's `ty::layout` lays out
+/// a struct: each field's offset is rounded up to its own alignment, then the
+/// next field starts right after it. Used to give every scalar column enough
+/// alignment to hand out `&T`/`&mut T` references without UB, instead of the
+/// byte-packed allocation a `#[repr(packed)]` source struct would otherwise
+/// imply.
+pub const fn compute_field_layouts<const N: usize>(
+    sizes: &[usize; N],
+    aligns: &[usize; N],
+) -> [FieldLayout; N] {
+    let mut out = [FieldLayout { offset: 0, size: 0, align: 1 }; N];
+    let mut offset = 0usize;
+    let mut i = 0;
+    while i < N {
+        let align = aligns[i];
+        offset = (offset + align - 1) & !(align - 1);
+        out[i] = FieldLayout { offset, size: sizes[i], align };
+        offset += sizes[i];
+        i += 1;
+    }
+    out
+}
+
+/// Type-erased layout and destructor info for a single dismembered leaf field.
+///
+/// # Purpose
+/// Owned (non-`Copy`) [`Component`] leaves, such as `String` or `Vec<T>`,
+/// cannot be restored from raw bytes the way plain-old-data can; a column
+/// holding them must instead run their destructor over every occupied slot
+/// before that memory is overwritten or freed. `TypeInfo` is how a leaf type
+/// hands the archetype the one piece of information it's missing: an erased
+/// `drop_fn`, monomorphized once per concrete type via [`core::ptr::drop_in_place`].
+#[derive(Clone, Copy)]
+pub struct TypeInfo {
+    pub size: usize,
+    pub align: usize,
+    pub drop_fn: Option<unsafe fn(*mut u8)>,
+}
+
+impl TypeInfo {
+    pub const fn of<T>() -> Self {
+        TypeInfo {
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+            drop_fn: if core::mem::needs_drop::<T>() {
+                Some(drop_glue::<T>)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+unsafe fn drop_glue<T>(ptr: *mut u8) {
+    unsafe { core::ptr::drop_in_place(ptr as *mut T) }
+}
+
+/// Implements [`Component`] for an owned, non-`Copy` leaf type, wiring up its
+/// destructor via [`TypeInfo`] so the archetype runs it instead of leaking.
+///
+/// # Usage
+/// ```
+///     use lynx_traits::impl_owned_component;
+///     pub struct Name(String);
+///     impl_owned_component!(Name);
+/// ```
+#[macro_export]
+macro_rules! impl_owned_component {
+    ($ty:ty) => {
+        impl $crate::Component for $ty {
+            type DismemberedOutput = $ty;
+            const COUNT: usize = 1;
+            const SIZES: [usize; 1] = [size_of::<$ty>()];
+            const ALIGNS: [usize; 1] = [align_of::<$ty>()];
+            const FIELD_LAYOUTS: [$crate::FieldLayout; 1] =
+                $crate::compute_field_layouts(&Self::SIZES, &Self::ALIGNS);
+            const ID: u32 = 0;
+
+            fn dismember(self) -> Self::DismemberedOutput {
+                self
+            }
+
+            fn dismembered_type_count() -> u32 {
+                Self::COUNT as u32
+            }
+
+            // Like the blanket `Copy` impl below, this macro is shared by
+            // every type it's invoked for, so `ID` can't be a distinct
+            // per-type constant; resolve through the same process-wide
+            // registry instead.
+            fn id() -> u32 {
+                $crate::registry_id::<$ty>()
+            }
+
+            fn drop_fns() -> &'static [Option<unsafe fn(*mut u8)>] {
+                const INFO: $crate::TypeInfo = $crate::TypeInfo::of::<$ty>();
+                &[INFO.drop_fn]
+            }
+        }
+    };
+}
+
+impl_owned_component!(String);
+
+impl<T: 'static> Component for Vec<T> {
+    type DismemberedOutput = Vec<T>;
+    const COUNT: usize = 1;
+    const SIZES: [usize; 1] = [size_of::<Vec<T>>()];
+    const ALIGNS: [usize; 1] = [align_of::<Vec<T>>()];
+    const FIELD_LAYOUTS: [FieldLayout; 1] = compute_field_layouts(&Self::SIZES, &Self::ALIGNS);
+    const ID: u32 = 0;
+
+    fn dismember(self) -> Self::DismemberedOutput {
+        self
+    }
+
+    fn dismembered_type_count() -> u32 {
+        Self::COUNT as u32
+    }
+
+    // This impl is generic over every `T`, so `ID` can't be a distinct
+    // per-element-type constant; resolve through the registry instead, the
+    // same as `impl_owned_component!` and the blanket `Copy` impl below.
+    fn id() -> u32 {
+        registry_id::<Vec<T>>()
+    }
+
+    fn drop_fns() -> &'static [Option<unsafe fn(*mut u8)>] {
+        const INFO: TypeInfo = TypeInfo::of::<Vec<T>>();
+        &[INFO.drop_fn]
+    }
+}
 
 /// This is the cornerstone of the engine.
 ///
@@ -10,7 +227,7 @@ use tabled::Tabled;
 ///     - i8, i16, i32, i64, i128
 ///     - f32, f64
 ///     - bool
-///     - Any kind of reference (mutable or not)
+///     - Any `'static` reference (mutable or not)
 ///
 /// This trait isn't applicable for allocated types, if you really need an allocated type inside a
 /// Component, you should store a reference.
@@ -20,38 +237,83 @@ use tabled::Tabled;
 pub trait Component {
     type DismemberedOutput;
     const COUNT: usize;
+
+    /// Byte size of each dismembered leaf field, computed at compile time by
+    /// concatenating each field type's own `SIZES` (see [`concat_sizes`]).
+    const SIZES: [usize; Self::COUNT];
+
+    /// Alignment of each dismembered leaf field, concatenated the same way
+    /// as `SIZES`.
+    const ALIGNS: [usize; Self::COUNT];
+
+    /// Per-dismembered-field `(offset, size, align)`, computed from `SIZES`
+    /// and `ALIGNS` by [`compute_field_layouts`].
+    const FIELD_LAYOUTS: [FieldLayout; Self::COUNT];
+
+    /// This component's id, assigned once at derive time. Blanket impls that
+    /// cover more than one concrete type (such as `Copy`'s below) cannot give
+    /// this a distinct value per type, so [`Component::id()`] is the
+    /// accessor callers should use; it falls back to this constant but may
+    /// be overridden to resolve the id some other way.
+    const ID: u32;
+
     fn dismember(self) -> Self::DismemberedOutput;
 
     fn dismembered_type_count() -> u32;
-    fn id() -> u32;
 
-    fn sizes() -> &'static [usize];
-}
+    fn id() -> u32 {
+        Self::ID
+    }
 
-/// The temporary solution for component ID registration, this will be replaced by some other memory
-/// and time-efficient solution.
-pub struct ComponentRegistry {
-    pub components: HashMap<TypeId, u32>,
-    pub next_id: u32,
-}
+    fn sizes() -> &'static [usize] {
+        &Self::SIZES
+    }
 
-impl ComponentRegistry {
-    pub fn id<T: 'static>(&mut self) -> u32 {
-        match self.components.get(&TypeId::of::<T>()) {
-            Some(id) => *id,
-            None => {
-                self.components.insert(TypeId::of::<T>(), self.next_id);
-                self.next_id += 1;
-                *self.components.get(&TypeId::of::<T>()).unwrap()
-            }
-        }
+    /// See [`Component::FIELD_LAYOUTS`].
+    fn field_layouts() -> &'static [FieldLayout] {
+        &Self::FIELD_LAYOUTS
     }
+
+    /// Per-dismembered-field destructor glue, one entry per `sizes()` slot.
+    /// `None` for `Copy`/plain-old-data leaves, which is the common case and
+    /// needs no drop at all.
+    fn drop_fns() -> &'static [Option<unsafe fn(*mut u8)>];
 }
 
-impl<'a, T: Copy + 'a> Component for T {
+/// Process-wide registry handing out collision-free component ids at
+/// runtime, keyed by [`TypeId`].
+///
+/// # Purpose
+/// The blanket [`Component`] impl below is a single generic `impl` shared by
+/// every `Copy` type, so it cannot bake a distinct value into its `const ID`
+/// the way `#[derive(Component)]` does per-struct at macro-expansion time —
+/// there is only one `ID = 0` for the whole impl. [`Component::id()`]
+/// sidesteps that by looking the concrete type up here instead, assigning it
+/// a fresh id off `NEXT_ID` the first time it's seen and memoizing it so
+/// every later call returns the same value.
+///
+/// Registry ids count up from `1 << 31`, so they never equal the `0` used for
+/// padding slots by [`concat_component_ids_dyn`] or the ids
+/// `#[derive(Component)]` counts up from `1`.
+static COMPONENT_IDS: OnceLock<Mutex<HashMap<TypeId, u32>>> = OnceLock::new();
+static NEXT_ID: AtomicU32 = AtomicU32::new(1 << 31);
+
+pub fn registry_id<T: 'static>() -> u32 {
+    let registry = COMPONENT_IDS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut registry = registry.lock().unwrap();
+    *registry
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+impl<T: Copy + 'static> Component for T {
     type DismemberedOutput = T;
     const COUNT: usize = 1;
-    
+    const SIZES: [usize; 1] = [size_of::<T>()];
+    const ALIGNS: [usize; 1] = [align_of::<T>()];
+    const FIELD_LAYOUTS: [FieldLayout; 1] = compute_field_layouts(&Self::SIZES, &Self::ALIGNS);
+    const ID: u32 = 0;
+
     fn dismember(self) -> Self::DismemberedOutput {
         self
     }
@@ -60,10 +322,10 @@ impl<'a, T: Copy + 'a> Component for T {
     }
 
     fn id() -> u32 {
-        0
+        registry_id::<T>()
     }
 
-    fn sizes() -> &'static [usize] {
-        &[size_of::<T>()]
+    fn drop_fns() -> &'static [Option<unsafe fn(*mut u8)>] {
+        &[None]
     }
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+/// Marker trait for types whose all-zero-bytes bit pattern is a valid value.
+///
+/// # Safety
+/// Implementing this type is a promise that a block of memory set entirely
+/// to zero bytes is a valid `Self`.
+pub unsafe trait Zeroable: Sized {
+    fn zeroed() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+/// Marker trait for types that are safe to reinterpret from arbitrary bytes.
+///
+/// # Safety
+/// Implementing this type is a promise that `Self` has no padding bytes,
+/// that every bit pattern of `size_of::<Self>()` bytes is a valid `Self`,
+/// and that `Self` has no interior mutability.
+pub unsafe trait AnyBitPattern: Copy + 'static {}
+
+/// Marker trait for "Plain Old Data".
+///
+/// # Purpose
+/// This is the bound the [`crate::Column`](../lynx/data_structures/column/trait.Column.html)
+/// slice-casting methods rely on: a `Pod` type can be copied byte-for-byte,
+/// zero-initialized, and reinterpreted from raw column storage without
+/// any further checks beyond alignment and length.
+///
+/// # Safety
+/// Implementing this type is a promise that `Self: Copy + 'static`, has no
+/// padding bytes, every bit pattern is valid, and `Self` holds no interior
+/// mutability (i.e. its bytes fully determine its value).
+pub unsafe trait Pod: Zeroable + AnyBitPattern {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            unsafe impl Zeroable for $ty {}
+            unsafe impl AnyBitPattern for $ty {}
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
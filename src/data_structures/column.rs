@@ -1,14 +1,77 @@
+use lynx_traits::{AnyBitPattern, Pod};
 
 pub trait Column {
     fn new<T>() -> Self;
     fn new_with_size<T>(size: usize) -> Self;
     fn new_bytes_with_size(size: usize) -> Self;
+
+    /// Byte-oriented constructor that, unlike [`Column::new_bytes_with_size`]
+    /// (which always allocates with alignment 1), allocates with the given
+    /// `align`. Used when the caller knows the true alignment its bytes need
+    /// to be handed out as `&T`/`&mut T` without UB, such as the per-field
+    /// layout computed by [`lynx_traits::compute_field_layouts`].
+    fn new_bytes_with_align(size: usize, align: usize) -> Self;
     fn resize<T>(&mut self, old_cap: usize, new_cap: usize);
     fn resize_bytes(&mut self, old_cap: usize, new_cap: usize);
+
+    /// Size, in bytes, of the column's current allocation.
+    fn capacity(&self) -> usize;
+
+    /// Grows the column (typed in units of `T`) so it can hold at least
+    /// `required_cap` elements of `T`, following an amortized doubling
+    /// policy: `max(old_cap * 2, required_cap)`.
+    ///
+    /// Zero-sized `T` never allocates; the column is given a sentinel
+    /// capacity instead.
+    fn grow_amortized<T>(&mut self, old_cap: usize, required_cap: usize);
+
+    /// Reserves capacity for at least `additional` more elements of `T`
+    /// on top of `old_cap`, amortized via [`Column::grow_amortized`].
+    fn reserve<T>(&mut self, old_cap: usize, additional: usize);
+
+    /// Byte-oriented equivalent of [`Column::grow_amortized`], for columns
+    /// whose element type is only known as a byte width (as in derive-generated
+    /// code, which works from [`lynx_traits::Component::sizes`]).
+    fn grow_amortized_bytes(&mut self, old_cap: usize, required_cap: usize);
+
+    /// Byte-oriented equivalent of [`Column::reserve`].
+    fn reserve_bytes(&mut self, old_cap: usize, additional: usize);
+
     fn insert<T>(&mut self, index: usize, data: T);
     fn get<T>(&self, index: usize) -> T;
     fn fill<T>(&mut self, start: usize, end: usize, data: T);
     fn write_bytes(&mut self, start: usize, data: &[u8]);
+
+    /// Moves the `last` row's `width` bytes on top of `index`'s, keeping the
+    /// column densely packed after `index` is removed.
+    ///
+    /// # Usage
+    /// Callers are responsible for decrementing whatever row count tracks
+    /// this column once every parallel column has been swapped; this method
+    /// only moves bytes.
+    fn swap_remove_bytes(&mut self, index: usize, last: usize, width: usize);
+
+    /// Reinterprets the first `len` elements of the column's storage as `&[T]`.
+    ///
+    /// # Panics
+    /// Panics if the cast would be misaligned or `len` does not fit in the
+    /// column's current allocation. Use [`Column::try_as_slice`] if you need
+    /// to handle that case instead of panicking.
+    fn as_slice<T: Pod>(&self, len: usize) -> &[T];
+
+    /// Reinterprets the first `len` elements of the column's storage as `&[T]`,
+    /// checking that `data` is aligned to `T` and that `len * size_of::<T>()`
+    /// fits inside the column's current allocation.
+    fn try_as_slice<T: AnyBitPattern>(&self, len: usize) -> Result<&[T], CastError>;
+}
+
+/// Reasons a [`Column::try_as_slice`] cast can be refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CastError {
+    /// The column's data pointer is not aligned to `align_of::<T>()`.
+    Misaligned,
+    /// `len * size_of::<T>()` does not fit inside the column's allocation.
+    SizeMismatch,
 }
 
 
@@ -41,4 +104,42 @@ pub mod column_test {
         assert_eq!(size_of_val(&col), 8);
         assert_eq!(size_of_val(&complex_col), 8);
     }
+
+    #[test]
+    pub fn new_bytes_with_align_is_aligned() {
+        let col = SimpleColumn::new_bytes_with_align(8, 8);
+        assert_eq!(col.data.as_ptr() as usize % 8, 0);
+        assert_eq!(col.capacity(), 8);
+    }
+
+    #[test]
+    pub fn as_slice_reinterprets_written_bytes() {
+        let mut col = SimpleColumn::new_bytes_with_align(16, 4);
+        col.insert::<u32>(0, 1);
+        col.insert::<u32>(1, 2);
+        col.insert::<u32>(2, 3);
+        col.insert::<u32>(3, 4);
+
+        assert_eq!(col.as_slice::<u32>(4), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    pub fn try_as_slice_rejects_misalignment() {
+        // Shift the column's (8-aligned) allocation by one byte so the cast
+        // deterministically trips the alignment check, rather than relying
+        // on the allocator happening to hand back a misaligned pointer.
+        let col = SimpleColumn::new_bytes_with_align(16, 8);
+        let shifted = SimpleColumn {
+            data: std::ptr::NonNull::new(unsafe { col.data.as_ptr().add(1) }).unwrap(),
+            cap: col.cap - 1,
+            align: col.align,
+        };
+        assert_eq!(shifted.try_as_slice::<u64>(1), Err(CastError::Misaligned));
+    }
+
+    #[test]
+    pub fn try_as_slice_rejects_out_of_bounds_len() {
+        let col = SimpleColumn::new_bytes_with_align(8, 4);
+        assert_eq!(col.try_as_slice::<u32>(3), Err(CastError::SizeMismatch));
+    }
 }
\ No newline at end of file
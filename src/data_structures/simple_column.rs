@@ -1,6 +1,7 @@
 use std::alloc::{alloc, realloc, Layout};
 use std::ptr::NonNull;
-use crate::data_structures::column::Column;
+use crate::data_structures::column::{CastError, Column};
+use lynx_traits::{AnyBitPattern, Pod};
 
 /// Just a different name for a Raw pointer to bytes.
 ///
@@ -33,6 +34,16 @@ use crate::data_structures::column::Column;
 #[derive(Debug)]
 pub struct SimpleColumn {
     pub data: NonNull<u8>,
+    /// Size, in bytes, of the column's current allocation, or the sentinel
+    /// `usize::MAX` once [`Column::grow_amortized`] ran with a zero-sized `T`.
+    /// Tracked so that [`Column::try_as_slice`] can refuse casts that would
+    /// read out of bounds.
+    pub cap: usize,
+    /// Alignment the current allocation was made with. Must stay consistent
+    /// across every `realloc`/`dealloc` call against this allocation, since
+    /// the allocator requires the `Layout` passed to free/grow it to match
+    /// the one it was allocated with.
+    pub align: usize,
 }
 
 impl Column for SimpleColumn {
@@ -48,8 +59,11 @@ impl Column for SimpleColumn {
     /// ```
     /// The layout will be aligned to the type parameter.
     fn new<T>() -> Self {
+        let layout = Layout::array::<T>(4).unwrap();
         Self {
-            data: unsafe {NonNull::new(alloc(Layout::array::<T>(4).unwrap())).unwrap()}
+            data: unsafe {NonNull::new(alloc(layout)).unwrap()},
+            cap: layout.size(),
+            align: layout.align(),
         }
     }
 
@@ -63,8 +77,11 @@ impl Column for SimpleColumn {
     ///     let mut col = SimpleColumn::new_with_size::<u32>(100);
     /// ```
     fn new_with_size<T>(size: usize) -> Self {
+        let layout = Layout::array::<T>(size * 4).unwrap();
         Self {
-            data: unsafe {NonNull::new(alloc(Layout::array::<T>(size * 4).unwrap())).unwrap()}
+            data: unsafe {NonNull::new(alloc(layout)).unwrap()},
+            cap: layout.size(),
+            align: layout.align(),
         }
     }
 
@@ -89,8 +106,30 @@ impl Column for SimpleColumn {
     ///     col.write_bytes(0, unsafe {&*slice});
     /// ```
     fn new_bytes_with_size(size: usize) -> Self {
+        let layout = Layout::array::<u8>(size).unwrap();
         Self {
-            data: unsafe {NonNull::new(alloc(Layout::array::<u8>(size).unwrap())).unwrap()}
+            data: unsafe {NonNull::new(alloc(layout)).unwrap()},
+            cap: layout.size(),
+            align: layout.align(),
+        }
+    }
+
+    /// See [`Column::new_bytes_with_align`].
+    ///
+    /// # Usage
+    /// ```
+    ///     use lynx::data_structures::column::Column;
+    ///     use lynx::data_structures::simple_column::SimpleColumn;
+    ///
+    ///     // Allocates 8 bytes aligned to 4, enough for two u32-aligned fields.
+    ///     let mut col = SimpleColumn::new_bytes_with_align(8, 4);
+    /// ```
+    fn new_bytes_with_align(size: usize, align: usize) -> Self {
+        let layout = Layout::from_size_align(size, align).unwrap();
+        Self {
+            data: unsafe {NonNull::new(alloc(layout)).unwrap()},
+            cap: layout.size(),
+            align,
         }
     }
 
@@ -106,8 +145,8 @@ impl Column for SimpleColumn {
     ///     col.resize::<u32>(1, 10);
     /// ```
     fn resize<T>(&mut self, old_cap: usize, new_cap: usize) {
-        let new_layout = Layout::array::<T>(new_cap * size_of::<T>()).unwrap();
-        let old_layout = Layout::array::<T>(old_cap * size_of::<T>()).unwrap();
+        let new_layout = Layout::from_size_align(Layout::array::<T>(new_cap).unwrap().size(), self.align).unwrap();
+        let old_layout = Layout::from_size_align(Layout::array::<T>(old_cap).unwrap().size(), self.align).unwrap();
         let old_ptr = self.data.as_ptr();
 
         unsafe {
@@ -117,6 +156,7 @@ impl Column for SimpleColumn {
                 None  => panic!("Column allocation failed")
             };
         }
+        self.cap = new_layout.size();
     }
 
 
@@ -142,8 +182,8 @@ impl Column for SimpleColumn {
     /// ```
     fn resize_bytes(&mut self, old_cap: usize, new_cap: usize) {
         //println!("Old cap: {}\tNext Cap: {}", old_cap, new_cap);
-        let new_layout = Layout::array::<u8>(new_cap).unwrap();
-        let old_layout = Layout::array::<u8>(old_cap).unwrap();
+        let new_layout = Layout::from_size_align(new_cap, self.align).unwrap();
+        let old_layout = Layout::from_size_align(old_cap, self.align).unwrap();
         let old_ptr = self.data.as_ptr();
         unsafe {
             let new_ptr = realloc(old_ptr, old_layout, new_layout.size());
@@ -152,8 +192,45 @@ impl Column for SimpleColumn {
                 None  => panic!("Column allocation failed")
             };
         }
+        self.cap = new_layout.size();
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Grows following the `RawVec` amortized-doubling policy: `max(old_cap * 2, required_cap)`.
+    ///
+    /// # Usage
+    /// ```
+    ///     use lynx::data_structures::column::Column;
+    ///     use lynx::data_structures::simple_column::SimpleColumn;
+    ///     let mut col = SimpleColumn::new::<u32>();
+    ///     col.grow_amortized::<u32>(4, 5);
+    /// ```
+    fn grow_amortized<T>(&mut self, old_cap: usize, required_cap: usize) {
+        if size_of::<T>() == 0 {
+            self.cap = usize::MAX;
+            return;
+        }
+        let new_cap = core::cmp::max(old_cap * 2, required_cap);
+        new_cap.checked_mul(size_of::<T>()).expect("Column capacity overflow");
+        self.resize::<T>(old_cap, new_cap);
+    }
+
+    fn reserve<T>(&mut self, old_cap: usize, additional: usize) {
+        self.grow_amortized::<T>(old_cap, old_cap + additional);
     }
 
+    fn grow_amortized_bytes(&mut self, old_cap: usize, required_cap: usize) {
+        let new_cap = core::cmp::max(old_cap * 2, required_cap);
+        self.resize_bytes(old_cap, new_cap);
+    }
+
+    fn reserve_bytes(&mut self, old_cap: usize, additional: usize) {
+        self.grow_amortized_bytes(old_cap, old_cap + additional);
+    }
 
     /// Inserts a value into the [`crate::data_structures::column::Column`].
     ///
@@ -226,4 +303,35 @@ impl Column for SimpleColumn {
             core::ptr::copy(data.as_ptr(), self.data.as_ptr().add(start * data.len()), data.len());
         }
     }
+
+    /// See [`Column::swap_remove_bytes`].
+    fn swap_remove_bytes(&mut self, index: usize, last: usize, width: usize) {
+        if index == last {
+            return;
+        }
+        unsafe {
+            let base = self.data.as_ptr();
+            core::ptr::copy(base.add(last * width), base.add(index * width), width);
+        }
+    }
+
+    /// See [`Column::as_slice`].
+    ///
+    /// # Panics
+    /// Panics on misalignment or if `len` does not fit the column's allocation.
+    fn as_slice<T: Pod>(&self, len: usize) -> &[T] {
+        self.try_as_slice::<T>(len).expect("SimpleColumn::as_slice: invalid cast")
+    }
+
+    /// See [`Column::try_as_slice`].
+    fn try_as_slice<T: AnyBitPattern>(&self, len: usize) -> Result<&[T], CastError> {
+        if (self.data.as_ptr() as usize) % core::mem::align_of::<T>() != 0 {
+            return Err(CastError::Misaligned);
+        }
+        let required = len.checked_mul(size_of::<T>()).ok_or(CastError::SizeMismatch)?;
+        if required > self.cap {
+            return Err(CastError::SizeMismatch);
+        }
+        Ok(unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const T, len) })
+    }
 }
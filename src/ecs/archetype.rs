@@ -1,4 +1,4 @@
-use lynx_traits::Component;
+use lynx_traits::{Component, Pod};
 use crate::data_structures::column::Column;
 
 pub trait Archetype {
@@ -20,6 +20,75 @@ pub trait Archetype {
     fn has<T: Component>(&self) -> bool;
     fn get_entity_count(&self) -> usize;
     fn set_entity_count(&mut self, count: usize);
+
+    /// Removes every row for which `f` returns `true`, via swap-remove (the
+    /// tail row is moved into the vacated slot, keeping columns densely
+    /// packed), across every column in lockstep. Because the tail row just
+    /// moved into `row` must itself be tested, `row` is not advanced after a
+    /// removal. Returns the indices that were removed, in removal order.
+    fn drain_filter<F: FnMut(usize) -> bool>(&mut self, f: F) -> Vec<usize>;
+
+    /// Keeps only the rows for which `f` returns `true`.
+    fn retain<F: FnMut(usize) -> bool>(&mut self, f: F);
+
+    /// Despawns the entity at `row` via swap-remove: `row`'s current value is
+    /// dropped, the tail row is moved into the vacated slot, and the entity
+    /// count is decremented. Callers that track entity identity across rows
+    /// (such as [`crate::ecs::world::World`]) must treat whichever entity
+    /// occupied the tail row as having moved into `row`.
+    fn despawn(&mut self, row: usize);
+
+    /// Gathers `T`'s bytes back from its scattered leaf columns at `row` and
+    /// reconstructs it — the inverse of [`Archetype::insert_component`],
+    /// which scatters `T`'s packed bytes across one column per dismembered
+    /// leaf field.
+    ///
+    /// Valid because every `#[derive(Component)]` struct is `#[repr(packed)]`:
+    /// its true in-memory layout has no padding, so re-concatenating each
+    /// leaf's bytes in declaration order reproduces `T`'s exact byte
+    /// representation, which [`core::ptr::read_unaligned`] can then read back.
+    ///
+    /// Bound to `T: Pod`, not just `Component`: an unaligned read back into
+    /// an arbitrary `T` is only sound if every bit pattern of `T`'s size is a
+    /// valid `T` and `T` has no padding, which is exactly what `Pod` promises.
+    fn read_component<T: Component + Pod>(&self, row: usize) -> Result<T, ArchetypeError> {
+        let sizes = <T as Component>::sizes();
+        let mut bytes = vec![0u8; sizes.iter().sum()];
+
+        let mut offset = 0;
+        for (field_position, &width) in sizes.iter().enumerate() {
+            let column = self.get::<T>(field_position)?;
+            for k in 0..width {
+                bytes[offset + k] = column.get::<u8>(row * width + k);
+            }
+            offset += width;
+        }
+        Ok(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) })
+    }
+
+    /// Scatters `value`'s packed bytes across `T`'s leaf columns at `row` —
+    /// the inverse of [`Archetype::read_component`]. Unlike
+    /// [`Archetype::insert_component`], this writes at an arbitrary existing
+    /// row instead of always appending at the current entity count.
+    ///
+    /// Bound to `T: Pod` for the same reason as [`Archetype::read_component`]:
+    /// scattering `value`'s bytes and later reading them back as `T` is only
+    /// sound for types `Pod` actually covers.
+    fn write_component<T: Component + Pod>(&mut self, row: usize, value: &T) -> Result<(), ArchetypeError> {
+        let sizes = <T as Component>::sizes();
+        let ptr = core::ptr::addr_of!(*value) as *const u8;
+
+        let mut offset = 0;
+        for (field_position, &width) in sizes.iter().enumerate() {
+            let column = self.get_mut::<T>(field_position)?;
+            for k in 0..width {
+                let byte = unsafe { core::ptr::read(ptr.add(offset + k)) };
+                column.insert::<u8>(row * width + k, byte);
+            }
+            offset += width;
+        }
+        Ok(())
+    }
 }
 
 
@@ -48,20 +117,20 @@ pub trait Archetype {
 ///
 ///     use lynx_derive::*;
 ///
-///    #[derive(Component)]
+///    #[derive(Component, Copy, Clone)]
 ///     pub struct A;
 ///
-///     #[derive(Component)]
+///     #[derive(Component, Copy, Clone)]
 ///     pub struct B;
 ///
-///     #[derive(Component)]
+///     #[derive(Component, Copy, Clone)]
 ///     pub struct C;
 ///
 ///     #[derive(Signature)]
 ///     #[repr(packed)]
 ///     pub struct DerivedSignature {
 ///         a: A, // <-------+
-///         b: B, // <-------+---> Must implement Component
+///         b: B, // <-------+---> Must implement Component + Pod (read_row/write_row need it)
 ///         c: C  // <-------+
 ///     }
 ///     // The resulting codegen from the above is the same as the below
@@ -69,11 +138,29 @@ pub trait Archetype {
 ///     // Implements Signature manually
 ///     pub struct TestSignature {
 ///         a: A, // <-------+
-///         b: B, // <-------+---> Must implement Component
+///         b: B, // <-------+---> Must implement Component + Pod (read_row/write_row need it)
 ///         c: C  // <-------+
 ///     }
 ///
 ///     impl Signature for TestSignature {
+///         const COUNT: usize = <A as Component>::COUNT + <B as Component>::COUNT + <C as Component>::COUNT;
+///         const IDS: [u32; Self::COUNT] = lynx_traits::concat_component_ids(&[
+///             (<A as Component>::ID, <A as Component>::COUNT),
+///             (<B as Component>::ID, <B as Component>::COUNT),
+///             (<C as Component>::ID, <C as Component>::COUNT),
+///         ]);
+///
+///         fn gen_ids() -> &'static [u32] {
+///             static IDS: std::sync::OnceLock<Vec<u32>> = std::sync::OnceLock::new();
+///             IDS.get_or_init(|| {
+///                 lynx_traits::concat_component_ids_dyn(&[
+///                     (<A as Component>::id(), <A as Component>::COUNT),
+///                     (<B as Component>::id(), <B as Component>::COUNT),
+///                     (<C as Component>::id(), <C as Component>::COUNT),
+///                 ])
+///             })
+///         }
+///
 ///         fn insert_components(&self, archetype: &mut impl Archetype) {
 ///             archetype.insert_component::<A>(&self.a).unwrap();
 ///             archetype.insert_component::<B>(&self.b).unwrap();
@@ -88,15 +175,26 @@ pub trait Archetype {
 ///             }
 ///         }
 ///
-///         fn gen_ids() -> &'static [u32] {
-///              &[0, 1, 2]
-///         }
-///
 ///          fn bulk(&self, archetype: &mut impl Archetype, times: usize) {
 ///              for _ in 0..times {
 ///                  self.insert_components(archetype);
 ///              }
 ///          }
+///
+///          fn read_row(archetype: &impl Archetype, row: usize) -> Result<Self, ArchetypeError> {
+///              Ok(Self {
+///                  a: archetype.read_component::<A>(row)?,
+///                  b: archetype.read_component::<B>(row)?,
+///                  c: archetype.read_component::<C>(row)?,
+///              })
+///          }
+///
+///          fn write_row(&self, archetype: &mut impl Archetype, row: usize) -> Result<(), ArchetypeError> {
+///              archetype.write_component::<A>(row, &self.a)?;
+///              archetype.write_component::<B>(row, &self.b)?;
+///              archetype.write_component::<C>(row, &self.c)?;
+///              Ok(())
+///          }
 ///     }
 /// ```
 ///
@@ -126,46 +224,124 @@ pub trait Archetype {
 ///     }
 /// ```
 pub trait Signature {
+    /// Total number of dismembered leaf fields across every `Component` in
+    /// this signature; the length of [`Signature::IDS`].
+    const COUNT: usize;
+
+    /// Component ids for every dismembered leaf field, in column order,
+    /// computed at compile time (see [`lynx_traits::concat_component_ids`]).
+    const IDS: [u32; Self::COUNT];
+
     fn insert_components(&self, archetype: &mut impl Archetype);
     fn create(archetype: &mut impl Archetype);
 
-    fn gen_ids() -> &'static [u32];
+    fn gen_ids() -> &'static [u32] {
+        &Self::IDS
+    }
 
     fn bulk(&self, archetype: &mut impl Archetype, times: usize);
+
+    /// Reconstructs this signature's component values at `row`, gathering
+    /// each component back from its columns via [`Archetype::read_component`].
+    /// The inverse of [`Signature::insert_components`]; the backbone of
+    /// [`crate::ecs::query::QueryIter`].
+    ///
+    /// Requires `Self: Copy`: reading a row hands back a bitwise copy of its
+    /// bytes, while the archetype still owns (and will eventually drop) the
+    /// original. That's only sound for `Copy` leaf components — anything
+    /// holding a heap allocation (a `String`/`Vec<T>` leaf) would end up
+    /// double-freed once both copies are dropped.
+    fn read_row(archetype: &impl Archetype, row: usize) -> Result<Self, ArchetypeError>
+    where
+        Self: Sized + Copy;
+
+    /// Scatters this signature's component values into `archetype` at `row`
+    /// — the inverse of [`Signature::read_row`], used by
+    /// [`crate::ecs::query::QueryIterMut`] to write mutations back.
+    fn write_row(&self, archetype: &mut impl Archetype, row: usize) -> Result<(), ArchetypeError>
+    where
+        Self: Copy;
 }
 
 #[macro_export]
 macro_rules! tuple_impls {
-    ( $( $name:ident )+ ) => {
-        impl<$($name: Component),+> Signature for ($($name,)+) {
+    ( $( $name:ident : $idx:tt )+ ) => {
+        impl<$($name: Component + Pod),+> Signature for ($($name,)+) {
+            const COUNT: usize = 0 $(+ <$name as Component>::COUNT)+;
+            const IDS: [u32; Self::COUNT] = lynx_traits::concat_component_ids(&[
+                $( (<$name as Component>::ID, <$name as Component>::COUNT) ),+
+            ]);
+
+            /// Built from each component's runtime [`Component::id`] (not
+            /// `Self::IDS`/`Component::ID`), once per monomorphization, so
+            /// raw `Copy` leaf types resolve to their registry id instead of
+            /// the `0` every such type's `ID` constant shares.
+            fn gen_ids() -> &'static [u32] {
+                // A `static` in a generic impl is shared by every tuple type
+                // of this arity, so key the cached lists by the tuple's type.
+                static IDS: std::sync::OnceLock<
+                    std::sync::Mutex<std::collections::HashMap<std::any::TypeId, &'static [u32]>>,
+                > = std::sync::OnceLock::new();
+                let ids = IDS.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+                let mut ids = ids.lock().unwrap();
+                *ids.entry(std::any::TypeId::of::<Self>()).or_insert_with(|| {
+                    lynx_traits::concat_component_ids_dyn(&[
+                        $( (<$name as Component>::id(), <$name as Component>::COUNT) ),+
+                    ]).leak()
+                })
+            }
+
             fn insert_components(&self, archetype: &mut impl Archetype) {
-                todo!()
+                $(archetype.insert_component::<$name>(&self.$idx).unwrap();)+
+                archetype.set_entity_count(archetype.get_entity_count() + 1);
+                if archetype.column_must_resize() {
+                    $({
+                        let sizes = <$name as Component>::sizes();
+                        for (index, value) in sizes.iter().enumerate() {
+                            let column = archetype.get_mut::<$name>(index).unwrap();
+                            let cap = column.capacity();
+                            column.reserve_bytes(cap, *value);
+                        }
+                    })+
+                }
             }
+
             fn create(archetype: &mut impl Archetype) {
-                todo!()
-            }
-            fn gen_ids() -> &'static [u32] {
-                todo!()
+                if archetype.get_entity_count() == 0 {
+                    $(archetype.initialize_column::<$name>();)+
+                }
             }
 
             fn bulk(&self, archetype: &mut impl Archetype, times: usize) {
-                todo!()
+                for _ in 0..times {
+                    self.insert_components(archetype);
+                }
+            }
+
+            fn read_row(archetype: &impl Archetype, row: usize) -> Result<Self, ArchetypeError> {
+                Ok(($(archetype.read_component::<$name>(row)?,)+))
+            }
+
+            fn write_row(&self, archetype: &mut impl Archetype, row: usize) -> Result<(), ArchetypeError> {
+                $(archetype.write_component::<$name>(row, &self.$idx)?;)+
+                Ok(())
             }
         }
     };
 }
 
-//tuple_impls! { A B }
-//tuple_impls! { A B C }
-//tuple_impls! { A B C D }
-//tuple_impls! { A B C D E }
-//tuple_impls! { A B C D E F}
-//tuple_impls! { A B C D E F G}
-//tuple_impls! { A B C D E F G H}
-//tuple_impls! { A B C D E F G H I}
-//tuple_impls! { A B C D E F G H I J}
-//tuple_impls! { A B C D E F G H I J K}
-//tuple_impls! { A B C D E F G H I J K L}
+tuple_impls! { A:0 }
+tuple_impls! { A:0 B:1 }
+tuple_impls! { A:0 B:1 C:2 }
+tuple_impls! { A:0 B:1 C:2 D:3 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 G:6 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 }
+tuple_impls! { A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ArchetypeError {
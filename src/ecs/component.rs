@@ -53,11 +53,20 @@ pub mod component_test {
     #[test]
 
     fn component_id_test() {
-        assert_eq!(f32::id(), 0);
         assert_eq!(Player::id(), 1);
         assert_eq!(Vector2::id(), 2);
         assert_eq!(RigidBody::id(), 4);
         assert_eq!(RigidBody::id(), 4);
+
+        // Copy components resolve their id through the process-wide
+        // registry: the same type always gets back the same id, and
+        // distinct types never collide (they used to, all reporting 0).
+        let f32_id = f32::id();
+        assert_eq!(f32::id(), f32_id);
+        let u32_id = u32::id();
+        assert_ne!(f32_id, u32_id);
+        assert_ne!(f32_id, bool::id());
+        assert_ne!(u32_id, bool::id());
     }
 
     #[test]
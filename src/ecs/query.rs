@@ -0,0 +1,203 @@
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use crate::ecs::archetype::{Archetype, Signature};
+
+/// Iterates every live row of an [`Archetype`], yielding `T`'s component
+/// values reconstructed via [`Signature::read_row`].
+///
+/// # Purpose
+/// [`Archetype::query`]/[`Archetype::query_mut`] hand back raw parallel
+/// columns, leaving callers to manually index rows and zip columns — error
+/// prone, and exactly the "projection over the table" ergonomics
+/// [`Signature`]'s own docs call for but don't provide. `QueryIter` is that
+/// projection: `for (pos, vel) in QueryIter::<_, (Position, Velocity)>::new(&arch)`
+/// walks every row and reassembles each component from its scattered leaf
+/// columns, instead of the caller hand-indexing them.
+///
+/// Bound to `T: Copy`: see [`Signature::read_row`] for why.
+pub struct QueryIter<'a, A: Archetype, T: Signature + Copy> {
+    archetype: &'a A,
+    row: usize,
+    len: usize,
+    _signature: PhantomData<T>,
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> QueryIter<'a, A, T> {
+    pub fn new(archetype: &'a A) -> Self {
+        Self {
+            archetype,
+            row: 0,
+            len: archetype.get_entity_count(),
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> Iterator for QueryIter<'a, A, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.len {
+            return None;
+        }
+        let value = T::read_row(self.archetype, self.row).ok()?;
+        self.row += 1;
+        Some(value)
+    }
+}
+
+/// Mutable counterpart to [`QueryIter`]: walks every live row, handing back
+/// a [`RowMut`] guard per row instead of a plain value.
+///
+/// # Purpose
+/// Every dismembered leaf field lives in its own column, so there is no
+/// contiguous `T` in memory to hand out `&mut T` into directly (see
+/// [`crate::ecs::simple_archetype::SimpleArchetype`]). `RowMut` works around
+/// that the same way every archetypal ECS with per-field storage does:
+/// gather a row's bytes into an owned `T` on [`Iterator::next`], let the
+/// caller mutate it through `DerefMut`, then scatter it back into its
+/// columns when the guard drops. Successive rows never alias each other's
+/// bytes, so holding one `RowMut` at a time is sound even though it reaches
+/// back into the archetype through a raw pointer to sidestep the borrow
+/// checker's single-mutable-borrow-per-call-to-`next` restriction.
+pub struct QueryIterMut<'a, A: Archetype, T: Signature + Copy> {
+    archetype: *mut A,
+    row: usize,
+    len: usize,
+    _marker: PhantomData<&'a mut A>,
+    _signature: PhantomData<T>,
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> QueryIterMut<'a, A, T> {
+    pub fn new(archetype: &'a mut A) -> Self {
+        let len = archetype.get_entity_count();
+        Self {
+            archetype: archetype as *mut A,
+            row: 0,
+            len,
+            _marker: PhantomData,
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> Iterator for QueryIterMut<'a, A, T> {
+    type Item = RowMut<'a, A, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.len {
+            return None;
+        }
+        // Safety: `self.archetype` outlives `'a` by construction (it came
+        // from an `&'a mut A`), and rows are visited strictly in order, so
+        // no two `RowMut`s this iterator hands out ever touch the same row
+        // at once.
+        let value = T::read_row(unsafe { &*self.archetype }, self.row).ok()?;
+        let row = self.row;
+        self.row += 1;
+        Some(RowMut {
+            archetype: self.archetype,
+            row,
+            value,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A single mutable row yielded by [`QueryIterMut`]. Writes `value` back
+/// into its archetype columns (via [`Signature::write_row`]) when dropped.
+pub struct RowMut<'a, A: Archetype, T: Signature + Copy> {
+    archetype: *mut A,
+    row: usize,
+    value: T,
+    _marker: PhantomData<&'a mut A>,
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> Deref for RowMut<'a, A, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> DerefMut for RowMut<'a, A, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<'a, A: Archetype, T: Signature + Copy> Drop for RowMut<'a, A, T> {
+    fn drop(&mut self) {
+        // Safety: see the safety comment in `QueryIterMut::next`.
+        let archetype = unsafe { &mut *self.archetype };
+        let _ = self.value.write_row(archetype, self.row);
+    }
+}
+
+#[cfg(test)]
+pub mod query_test {
+    use super::*;
+    use crate::ecs::archetype::ArchetypeError;
+    use crate::ecs::simple_archetype::SimpleArchetype;
+    use lynx_derive::{Component, Signature};
+
+    // `QueryIter`/`QueryIterMut` bitwise-copy rows back out of their columns
+    // (see `Signature::read_row`), so only `Copy` components are safe to
+    // project through them; these two are local to the test so the
+    // already-`!Copy` `Player`/`Vector2` used elsewhere stay untouched.
+    #[derive(Component, Clone, Copy, Debug)]
+    #[repr(packed)]
+    struct Id(u32);
+
+    #[derive(Component, Clone, Copy, Debug)]
+    #[repr(packed)]
+    struct Pos {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Signature, Clone, Copy)]
+    struct IdPos {
+        id: Id,
+        pos: Pos,
+    }
+
+    #[test]
+    fn query_iter_reassembles_every_row() {
+        let mut arch = SimpleArchetype::new::<IdPos>();
+        arch.insert(IdPos {
+            id: Id(1),
+            pos: Pos { x: 1.0, y: 2.0 },
+        });
+        arch.insert(IdPos {
+            id: Id(2),
+            pos: Pos { x: 3.0, y: 4.0 },
+        });
+
+        let rows: Vec<IdPos> = QueryIter::<_, IdPos>::new(&arch).collect();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].id.0, 1);
+        assert_eq!(rows[0].pos.x, 1.0);
+        assert_eq!(rows[1].id.0, 2);
+        assert_eq!(rows[1].pos.y, 4.0);
+    }
+
+    #[test]
+    fn query_iter_mut_writes_mutations_back() {
+        let mut arch = SimpleArchetype::new::<IdPos>();
+        arch.insert(IdPos {
+            id: Id(1),
+            pos: Pos { x: 1.0, y: 2.0 },
+        });
+
+        for mut row in QueryIterMut::<_, IdPos>::new(&mut arch) {
+            row.id.0 += 10;
+        }
+
+        let rows: Vec<IdPos> = QueryIter::<_, IdPos>::new(&arch).collect();
+        assert_eq!(rows[0].id.0, 11);
+    }
+}
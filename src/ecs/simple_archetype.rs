@@ -5,6 +5,7 @@ use crate::ecs::component::{Player, RigidBody, Vector2};
 use lynx_derive::Signature;
 use lynx_traits::Component;
 use std::alloc::{dealloc, Layout};
+use std::collections::HashMap;
 
 /// This represents entities with a specific set of components ([`Signature`])
 ///
@@ -46,13 +47,46 @@ pub struct SimpleArchetype {
     // Must become thread-safe
     pub entity_count: u32,
     pub columns: Vec<SimpleColumn>,
+    /// Width, in bytes, of a single row's worth of each column in `columns`,
+    /// in the same order; used by row-oriented operations like [`Archetype::drain_filter`].
+    pub widths: Vec<usize>,
+    /// Destructor glue for each column in `columns`, in the same order;
+    /// `None` for plain-old-data columns, which never need a drop pass.
+    pub drop_fns: Vec<Option<unsafe fn(*mut u8)>>,
     pub type_to_col: &'static [u32],
+    /// Whole-component ids present in this archetype, one entry per call to
+    /// [`Archetype::initialize_column`], in column order; used by
+    /// [`crate::ecs::world::World`] to compute archetype-transition targets.
+    pub component_ids: Vec<u32>,
+    /// `(start, len)` range into `columns` owned by each entry of
+    /// `component_ids`, in the same order.
+    pub component_ranges: Vec<(usize, usize)>,
+    /// Archetype-transition graph: component id -> index of the
+    /// [`crate::ecs::world::World`] archetype reached by adding that
+    /// component to this one.
+    pub add_edges: HashMap<u32, usize>,
+    /// Archetype-transition graph: component id -> index of the
+    /// [`crate::ecs::world::World`] archetype reached by removing that
+    /// component from this one.
+    pub remove_edges: HashMap<u32, usize>,
 }
 
 impl Drop for SimpleArchetype {
     fn drop(&mut self) {
+        if self.drop_fns.iter().any(Option::is_some) {
+            let count = self.entity_count as usize;
+            for ((column, width), drop_fn) in
+                self.columns.iter().zip(self.widths.iter()).zip(self.drop_fns.iter())
+            {
+                if let Some(drop_fn) = drop_fn {
+                    for row in 0..count {
+                        unsafe { drop_fn(column.data.as_ptr().add(row * width)) };
+                    }
+                }
+            }
+        }
         for i in self.columns.iter_mut() {
-            unsafe { dealloc(i.data.as_ptr(), Layout::new::<u8>()) };
+            unsafe { dealloc(i.data.as_ptr(), Layout::from_size_align(i.cap, i.align).unwrap()) };
         }
     }
 }
@@ -86,7 +120,13 @@ impl Archetype for SimpleArchetype {
         let mut arch = Self {
             entity_count: 0,
             columns: Vec::new(),
+            widths: Vec::new(),
+            drop_fns: Vec::new(),
             type_to_col: T::gen_ids(),
+            component_ids: Vec::new(),
+            component_ranges: Vec::new(),
+            add_edges: HashMap::new(),
+            remove_edges: HashMap::new(),
         };
         T::create(&mut arch);
         arch
@@ -446,6 +486,13 @@ impl Archetype for SimpleArchetype {
     #[inline(always)]
     fn insert<T: Signature>(&mut self, signature: T) {
         signature.insert_components(self);
+        // `insert_components` only memcpys each field's bytes into its
+        // column; for an owned (non-`Copy`) leaf (see `impl_owned_component!`)
+        // that copy shares `signature`'s original heap allocation, so letting
+        // `signature` drop normally here would free it out from under the
+        // column. The column now owns those bytes, so forget this shell
+        // instead of running its destructor.
+        core::mem::forget(signature);
     }
 
     fn fill<T: Signature>(&mut self, signature: &T, amount: usize) -> Result<(), ArchetypeError> {
@@ -476,11 +523,18 @@ impl Archetype for SimpleArchetype {
     /// ```
     fn initialize_column<T: Component>(&mut self) {
         //println!("Initializin column for: {:?}", std::any::type_name::<T>());
-        let sizes = <T as Component>::sizes();
+        let field_layouts = <T as Component>::field_layouts();
+        let drop_fns = <T as Component>::drop_fns();
+        let start = self.columns.len();
 
-        for value in sizes.iter() {
-            self.columns.push(SimpleColumn::new_bytes_with_size(*value));
+        for (layout, drop_fn) in field_layouts.iter().zip(drop_fns.iter()) {
+            self.columns
+                .push(SimpleColumn::new_bytes_with_align(layout.size, layout.align));
+            self.widths.push(layout.size);
+            self.drop_fns.push(*drop_fn);
         }
+        self.component_ids.push(<T as Component>::id());
+        self.component_ranges.push((start, field_layouts.len()));
     }
 
     #[inline(always)]
@@ -497,6 +551,55 @@ impl Archetype for SimpleArchetype {
     fn set_entity_count(&mut self, count: usize) {
         self.entity_count = count as u32;
     }
+
+    /// See [`Archetype::drain_filter`].
+    fn drain_filter<F: FnMut(usize) -> bool>(&mut self, mut f: F) -> Vec<usize> {
+        let any_drop_glue = self.drop_fns.iter().any(Option::is_some);
+        let mut removed = Vec::new();
+        let mut row = 0;
+        let mut count = self.entity_count as usize;
+        while row < count {
+            if f(row) {
+                removed.push(row);
+                let last = count - 1;
+                for ((column, width), drop_fn) in
+                    self.columns.iter_mut().zip(self.widths.iter()).zip(self.drop_fns.iter())
+                {
+                    if any_drop_glue {
+                        if let Some(drop_fn) = drop_fn {
+                            unsafe { drop_fn(column.data.as_ptr().add(row * width)) };
+                        }
+                    }
+                    column.swap_remove_bytes(row, last, *width);
+                }
+                count -= 1;
+                // The tail row was just swapped into `row`; re-test it instead of advancing.
+            } else {
+                row += 1;
+            }
+        }
+        self.entity_count = count as u32;
+        removed
+    }
+
+    /// See [`Archetype::retain`].
+    fn retain<F: FnMut(usize) -> bool>(&mut self, mut f: F) {
+        self.drain_filter(|row| !f(row));
+    }
+
+    /// See [`Archetype::despawn`].
+    fn despawn(&mut self, row: usize) {
+        let last = self.entity_count as usize - 1;
+        for ((column, width), drop_fn) in
+            self.columns.iter_mut().zip(self.widths.iter()).zip(self.drop_fns.iter())
+        {
+            if let Some(drop_fn) = drop_fn {
+                unsafe { drop_fn(column.data.as_ptr().add(row * width)) };
+            }
+            column.swap_remove_bytes(row, last, *width);
+        }
+        self.entity_count = last as u32;
+    }
 }
 
 #[derive(Signature)]
@@ -558,4 +661,117 @@ pub mod archetype_test {
         assert_eq!(arch.get::<RigidBody>(6).unwrap().get::<f32>(0), 19.2);
         assert_eq!(arch.get::<RigidBody>(7).unwrap().get::<f32>(0), 0.0);
     }
+
+    #[derive(Signature)]
+    struct VecOnly {
+        vector2: Vector2,
+    }
+
+    #[test]
+    pub fn drain_filter_removes_matching_rows_via_swap_remove() {
+        let mut arch = SimpleArchetype::new::<VecOnly>();
+        for i in 0..5 {
+            arch.insert(VecOnly {
+                vector2: Vector2 { x: i as f32, y: 0.0 },
+            });
+        }
+
+        // Mirrors `drain_filter`'s own swap-remove contract: when a row is
+        // removed, the tail value is swapped into its slot and must be
+        // re-tested rather than skipped.
+        let mut shadow: Vec<f32> = (0..5).map(|i| i as f32).collect();
+        let removed = arch.drain_filter(|row| {
+            let hit = shadow[row] == 1.0 || shadow[row] == 3.0;
+            if hit {
+                let last = shadow.len() - 1;
+                shadow.swap(row, last);
+                shadow.pop();
+            }
+            hit
+        });
+
+        assert_eq!(removed.len(), 2);
+        assert_eq!(arch.get_entity_count(), 3);
+        let mut remaining: Vec<f32> = (0..arch.get_entity_count())
+            .map(|row| arch.get::<Vector2>(0).unwrap().get::<f32>(row))
+            .collect();
+        remaining.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(remaining, vec![0.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    pub fn retain_keeps_only_rows_matching_predicate() {
+        let mut arch = SimpleArchetype::new::<VecOnly>();
+        for i in 0..4 {
+            arch.insert(VecOnly {
+                vector2: Vector2 { x: i as f32, y: 0.0 },
+            });
+        }
+
+        let mut shadow: Vec<f32> = (0..4).map(|i| i as f32).collect();
+        arch.retain(|row| {
+            let keep = shadow[row] % 2.0 == 0.0;
+            if !keep {
+                let last = shadow.len() - 1;
+                shadow.swap(row, last);
+                shadow.pop();
+            }
+            keep
+        });
+
+        assert_eq!(arch.get_entity_count(), 2);
+        let mut remaining: Vec<f32> = (0..arch.get_entity_count())
+            .map(|row| arch.get::<Vector2>(0).unwrap().get::<f32>(row))
+            .collect();
+        remaining.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(remaining, vec![0.0, 2.0]);
+    }
+
+    #[derive(Signature)]
+    struct NameOnly {
+        name: String,
+    }
+
+    #[test]
+    pub fn despawn_drops_owned_component_without_double_freeing() {
+        let mut arch = SimpleArchetype::new::<NameOnly>();
+        arch.insert(NameOnly { name: String::from("alpha") });
+        arch.insert(NameOnly { name: String::from("beta") });
+
+        // Despawning row 0 must run `String`'s destructor over exactly that
+        // row's bytes (via the drop glue `impl_owned_component!` wires up),
+        // then swap-remove "beta" into its place. If `SimpleArchetype::insert`
+        // ever let the original `NameOnly` values drop instead of forgetting
+        // their shells, "alpha"'s buffer would be freed twice — here, and
+        // again once the inserted value went out of scope — which aborts the
+        // process under a normal allocator.
+        arch.despawn(0);
+
+        assert_eq!(arch.get_entity_count(), 1);
+        let moved: String = arch.get::<String>(0).unwrap().get::<String>(0);
+        assert_eq!(moved, "beta");
+        // `get` reads the column's bytes out as an owned `String`, aliasing
+        // the heap buffer the archetype still owns; forget this copy so
+        // `arch`'s own `Drop` is the only thing that ever frees it.
+        core::mem::forget(moved);
+    }
+
+    #[test]
+    pub fn map_round_trips_for_tuple_of_distinct_raw_copy_types() {
+        // Regression test: a tuple `Signature` built entirely out of raw
+        // `Copy` types (no `#[derive(Component)]` involved) used to have its
+        // `type_to_col` built from `gen_ids()` while `map()` looked types up
+        // by `id()` — before `gen_ids()` was fixed to resolve through the
+        // same registry, every such type's `Component::ID` was `0`, so two
+        // distinct raw `Copy` components in one signature collided onto the
+        // same column.
+        let mut arch = SimpleArchetype::new::<(u32, f32)>();
+        arch.insert((7u32, 2.5f32));
+        arch.insert((11u32, 4.75f32));
+
+        assert_eq!(arch.get::<u32>(0).unwrap().get::<u32>(0), 7);
+        assert_eq!(arch.get::<f32>(0).unwrap().get::<f32>(0), 2.5);
+        assert_eq!(arch.get::<u32>(0).unwrap().get::<u32>(1), 11);
+        assert_eq!(arch.get::<f32>(0).unwrap().get::<f32>(1), 4.75);
+    }
 }
@@ -0,0 +1,537 @@
+use std::collections::HashMap;
+
+use lynx_traits::Component;
+
+use crate::data_structures::column::Column;
+use crate::data_structures::simple_column::SimpleColumn;
+use crate::ecs::archetype::{Archetype, ArchetypeError, Signature};
+use crate::ecs::simple_archetype::SimpleArchetype;
+
+/// Identifies a spawned entity independently of where it currently lives;
+/// looked up through [`World`]'s location map, which is rewritten as the
+/// entity's row changes under swap-remove despawns and archetype moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntityId(pub u32);
+
+/// Errors raised by [`World`]'s entity-level operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorldError {
+    EntityNotFound,
+    Archetype(ArchetypeError),
+}
+
+impl From<ArchetypeError> for WorldError {
+    fn from(err: ArchetypeError) -> Self {
+        WorldError::Archetype(err)
+    }
+}
+
+/// Owns every live [`SimpleArchetype`] and memoizes the archetype-transition
+/// graph walked by [`World::add_component`]/[`World::remove_component`].
+///
+/// # Purpose
+/// A [`SimpleArchetype`] only ever holds entities sharing one exact
+/// component set, so adding or removing a single component from a live
+/// entity always means relocating its row into a different archetype.
+/// Recomputing and searching for that target on every mutation would make
+/// structural edits O(components); instead, each archetype caches an "add"
+/// and a "remove" edge per component id, pointing straight at the
+/// neighbouring archetype reached by that one change, the same archetype
+/// graph hecs builds for its own structural edits.
+pub struct World {
+    pub archetypes: Vec<SimpleArchetype>,
+    by_signature: HashMap<Vec<u32>, usize>,
+    /// Per-archetype, per-row entity id, kept swap-removed in lockstep with
+    /// the archetype's own columns so a despawn can tell which entity moved
+    /// into the freed row.
+    entities: Vec<Vec<EntityId>>,
+    /// Where each live entity's row currently is: `EntityId -> (archetype_index, row)`.
+    locations: HashMap<EntityId, (usize, usize)>,
+    next_entity: u32,
+}
+
+impl World {
+    pub fn new() -> Self {
+        Self {
+            archetypes: Vec::new(),
+            by_signature: HashMap::new(),
+            entities: Vec::new(),
+            locations: HashMap::new(),
+            next_entity: 0,
+        }
+    }
+
+    /// Creates an archetype for `T` and registers it under its component-id
+    /// set, so later transitions can be routed straight to it.
+    pub fn new_archetype<T: Signature>(&mut self) -> usize {
+        let archetype = SimpleArchetype::new::<T>();
+        let mut ids = archetype.component_ids.clone();
+        ids.sort_unstable();
+        let index = self.archetypes.len();
+        self.by_signature.insert(ids, index);
+        self.archetypes.push(archetype);
+        self.entities.push(Vec::new());
+        index
+    }
+
+    /// Inserts `signature` into archetype `archetype` and assigns it a fresh
+    /// [`EntityId`], recording its location for later [`World::despawn`].
+    pub fn spawn<T: Signature>(&mut self, archetype: usize, signature: T) -> EntityId {
+        let row = self.archetypes[archetype].get_entity_count();
+        self.archetypes[archetype].insert(signature);
+
+        let id = EntityId(self.next_entity);
+        self.next_entity += 1;
+        self.entities[archetype].push(id);
+        self.locations.insert(id, (archetype, row));
+        id
+    }
+
+    /// Despawns `id` via [`Archetype::despawn`], then rewrites the location
+    /// of whichever entity the swap-remove moved into the freed row.
+    pub fn despawn(&mut self, id: EntityId) -> Result<(), WorldError> {
+        let (archetype, row) = self
+            .locations
+            .remove(&id)
+            .ok_or(WorldError::EntityNotFound)?;
+
+        self.archetypes[archetype].despawn(row);
+        self.entities[archetype].swap_remove(row);
+        if let Some(&moved) = self.entities[archetype].get(row) {
+            self.locations.insert(moved, (archetype, row));
+        }
+        Ok(())
+    }
+
+    /// Moves `id`'s entity into the archetype reached by adding component
+    /// `T`, writing `value` into its column, and returns its new
+    /// `(archetype_index, row)`. A no-op move if `id` already has `T`.
+    ///
+    /// Unlike a raw `(archetype, row)` pair, `id` stays valid across the
+    /// move: this rewrites [`World`]'s own `entities`/`locations` tracking
+    /// (the same bookkeeping [`World::spawn`]/[`World::despawn`] maintain),
+    /// so a later [`World::despawn`] or structural edit on `id` sees where it
+    /// actually ended up, not where it used to be.
+    pub fn add_component<T: Component>(
+        &mut self,
+        id: EntityId,
+        value: &T,
+    ) -> Result<(usize, usize), WorldError> {
+        let &(from, row) = self.locations.get(&id).ok_or(WorldError::EntityNotFound)?;
+        if self.archetypes[from].has::<T>() {
+            return Ok((from, row));
+        }
+
+        let cid = <T as Component>::id();
+        let to = match self.archetypes[from].add_edges.get(&cid) {
+            Some(&to) => to,
+            None => self.build_edge::<T>(from, cid, true),
+        };
+
+        let dst_row = self.move_shared_columns(from, to, row);
+        write_component(&mut self.archetypes[to], dst_row, value)?;
+        self.archetypes[to].set_entity_count(dst_row + 1);
+        swap_remove_row(&mut self.archetypes[from], row);
+        self.relocate(id, from, row, to, dst_row);
+        Ok((to, dst_row))
+    }
+
+    /// Moves `id`'s entity into the archetype reached by removing component
+    /// `T`, running `T`'s drop glue on the vacated value, and returns its new
+    /// `(archetype_index, row)`. See [`World::add_component`] for why `id`
+    /// rather than a raw `(archetype, row)` pair.
+    pub fn remove_component<T: Component>(
+        &mut self,
+        id: EntityId,
+    ) -> Result<(usize, usize), WorldError> {
+        let &(from, row) = self.locations.get(&id).ok_or(WorldError::EntityNotFound)?;
+        if !self.archetypes[from].has::<T>() {
+            return Err(WorldError::Archetype(ArchetypeError::ComponentNotFound));
+        }
+
+        let cid = <T as Component>::id();
+        let to = match self.archetypes[from].remove_edges.get(&cid) {
+            Some(&to) => to,
+            None => self.build_edge::<T>(from, cid, false),
+        };
+
+        drop_component::<T>(&self.archetypes[from], row);
+        let dst_row = self.move_shared_columns(from, to, row);
+        self.archetypes[to].set_entity_count(dst_row + 1);
+        swap_remove_row(&mut self.archetypes[from], row);
+        self.relocate(id, from, row, to, dst_row);
+        Ok((to, dst_row))
+    }
+
+    /// Rewrites `id`'s location to `(to, dst_row)` after [`World::add_component`]/
+    /// [`World::remove_component`] swap-removed it out of `from`'s `row`,
+    /// and — like [`World::despawn`] — fixes up whichever entity the
+    /// swap-remove pulled into the vacated slot.
+    fn relocate(&mut self, id: EntityId, from: usize, row: usize, to: usize, dst_row: usize) {
+        self.entities[from].swap_remove(row);
+        if let Some(&moved) = self.entities[from].get(row) {
+            self.locations.insert(moved, (from, row));
+        }
+        self.entities[to].push(id);
+        self.locations.insert(id, (to, dst_row));
+    }
+
+    /// Looks up or builds the archetype one edge away from `from` across
+    /// component `id`, memoizing the edge in both directions.
+    fn build_edge<T: Component>(&mut self, from: usize, id: u32, adding: bool) -> usize {
+        let mut ids = self.archetypes[from].component_ids.clone();
+        if adding {
+            ids.push(id);
+        } else {
+            ids.retain(|&existing| existing != id);
+        }
+        ids.sort_unstable();
+
+        let to = match self.by_signature.get(&ids) {
+            Some(&existing) => existing,
+            None => {
+                let skip = if adding { None } else { Some(id) };
+                let mut target = clone_layout(&self.archetypes[from], skip);
+                if adding {
+                    target.initialize_column::<T>();
+                    let mut type_to_col = target.type_to_col.to_vec();
+                    type_to_col.push(id);
+                    for _ in 1..<T as Component>::sizes().len() {
+                        type_to_col.push(0);
+                    }
+                    target.type_to_col = type_to_col.leak();
+                }
+                let index = self.archetypes.len();
+                self.archetypes.push(target);
+                self.entities.push(Vec::new());
+                self.by_signature.insert(ids, index);
+                index
+            }
+        };
+
+        if adding {
+            self.archetypes[from].add_edges.insert(id, to);
+            self.archetypes[to].remove_edges.insert(id, from);
+        } else {
+            self.archetypes[from].remove_edges.insert(id, to);
+            self.archetypes[to].add_edges.insert(id, from);
+        }
+        to
+    }
+
+    /// Copies every column archetype `to` shares with archetype `from` from
+    /// `row` into a freshly appended row of `to`, growing columns as needed.
+    /// Returns the row the entity landed on in `to`.
+    fn move_shared_columns(&mut self, from: usize, to: usize, row: usize) -> usize {
+        let (source, target) = borrow_two_mut(&mut self.archetypes, from, to);
+        let dst_row = target.entity_count as usize;
+
+        for (pos, &(tstart, tlen)) in target.component_ranges.iter().enumerate() {
+            let id = target.component_ids[pos];
+            let Some(spos) = source.component_ids.iter().position(|&sid| sid == id) else {
+                continue;
+            };
+            let (sstart, _) = source.component_ranges[spos];
+
+            for offset in 0..tlen {
+                let width = target.widths[tstart + offset];
+                let column = &mut target.columns[tstart + offset];
+                let required = (dst_row + 1) * width;
+                if required > column.capacity() {
+                    let cap = column.capacity();
+                    column.reserve_bytes(cap, width);
+                }
+                unsafe {
+                    let src_ptr = source.columns[sstart + offset].data.as_ptr().add(row * width);
+                    let dst_ptr = target.columns[tstart + offset]
+                        .data
+                        .as_ptr()
+                        .add(dst_row * width);
+                    core::ptr::copy_nonoverlapping(src_ptr, dst_ptr, width);
+                }
+            }
+        }
+        dst_row
+    }
+}
+
+/// Builds an empty archetype sharing `source`'s column layout, skipping the
+/// component `skip` (if any); the starting point for a one-component-away
+/// transition target.
+fn clone_layout(source: &SimpleArchetype, skip: Option<u32>) -> SimpleArchetype {
+    let mut target = SimpleArchetype {
+        entity_count: 0,
+        columns: Vec::new(),
+        widths: Vec::new(),
+        drop_fns: Vec::new(),
+        type_to_col: &[],
+        component_ids: Vec::new(),
+        component_ranges: Vec::new(),
+        add_edges: HashMap::new(),
+        remove_edges: HashMap::new(),
+    };
+
+    let mut type_to_col = Vec::new();
+    for (&id, &(start, len)) in source
+        .component_ids
+        .iter()
+        .zip(source.component_ranges.iter())
+    {
+        if Some(id) == skip {
+            continue;
+        }
+        let new_start = target.columns.len();
+        for i in start..start + len {
+            target.columns.push(SimpleColumn::new_bytes_with_align(
+                source.widths[i],
+                source.columns[i].align,
+            ));
+            target.widths.push(source.widths[i]);
+            target.drop_fns.push(source.drop_fns[i]);
+        }
+        target.component_ids.push(id);
+        target.component_ranges.push((new_start, len));
+
+        type_to_col.push(id);
+        for _ in 1..len {
+            type_to_col.push(0);
+        }
+    }
+    target.type_to_col = type_to_col.leak();
+    target
+}
+
+/// Writes `value`'s dismembered bytes into `archetype`'s columns for `T` at
+/// `row`, without touching `entity_count` — unlike [`Archetype::insert_component`],
+/// which always writes at the current entity count.
+fn write_component<T: Component>(
+    archetype: &mut SimpleArchetype,
+    row: usize,
+    value: &T,
+) -> Result<(), ArchetypeError> {
+    let col = archetype.map::<T>()?;
+    let sizes = <T as Component>::sizes();
+    let ptr = core::ptr::addr_of!(*value) as *const u8;
+
+    let mut offset = 0;
+    for (index, &width) in sizes.iter().enumerate() {
+        let column = &mut archetype.columns[col + index];
+        let required = (row + 1) * width;
+        if required > column.capacity() {
+            let cap = column.capacity();
+            column.reserve_bytes(cap, width);
+        }
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                ptr.add(offset),
+                archetype.columns[col + index].data.as_ptr().add(row * width),
+                width,
+            );
+        }
+        offset += width;
+    }
+    Ok(())
+}
+
+/// Runs `T`'s drop glue over its columns at `row`, for the component being
+/// dropped out of the archetype by [`World::remove_component`].
+fn drop_component<T: Component>(archetype: &SimpleArchetype, row: usize) {
+    let Ok(col) = archetype.map::<T>() else {
+        return;
+    };
+    for index in 0..<T as Component>::sizes().len() {
+        if let Some(drop_fn) = archetype.drop_fns[col + index] {
+            let width = archetype.widths[col + index];
+            unsafe { drop_fn(archetype.columns[col + index].data.as_ptr().add(row * width)) };
+        }
+    }
+}
+
+/// Swap-removes `row` out of `archetype` without running any drop glue, for
+/// entities whose values were already relocated to another archetype.
+fn swap_remove_row(archetype: &mut SimpleArchetype, row: usize) {
+    let last = archetype.entity_count as usize - 1;
+    for (column, width) in archetype.columns.iter_mut().zip(archetype.widths.iter()) {
+        column.swap_remove_bytes(row, last, *width);
+    }
+    archetype.entity_count = last as u32;
+}
+
+/// Borrows two distinct elements of `archetypes` mutably at once.
+fn borrow_two_mut(
+    archetypes: &mut [SimpleArchetype],
+    a: usize,
+    b: usize,
+) -> (&mut SimpleArchetype, &mut SimpleArchetype) {
+    assert_ne!(a, b, "cannot borrow the same archetype as both source and target");
+    if a < b {
+        let (left, right) = archetypes.split_at_mut(b);
+        (&mut left[a], &mut right[0])
+    } else {
+        let (left, right) = archetypes.split_at_mut(a);
+        (&mut right[0], &mut left[b])
+    }
+}
+
+#[cfg(test)]
+pub mod world_test {
+    use super::*;
+    use crate::ecs::component::{Player, Vector2};
+    use lynx_derive::Signature;
+
+    #[derive(Signature)]
+    struct VectorOnly {
+        vector2: Vector2,
+    }
+
+    #[test]
+    fn add_component_moves_entity_into_target_archetype() {
+        let mut world = World::new();
+        let base = world.new_archetype::<VectorOnly>();
+        let id = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 1.0, y: 2.0 },
+            },
+        );
+
+        let (target, row) = world.add_component::<Player>(id, &Player { id: 7 }).unwrap();
+
+        assert_ne!(target, base);
+        assert_eq!(world.archetypes[base].entity_count, 0);
+        assert_eq!(world.archetypes[target].entity_count, 1);
+        assert_eq!(
+            world.archetypes[target].get::<Vector2>(0).unwrap().get::<f32>(row),
+            1.0
+        );
+        assert_eq!(
+            world.archetypes[target].get::<Vector2>(1).unwrap().get::<f32>(row),
+            2.0
+        );
+        assert_eq!(
+            world.archetypes[target].get::<Player>(0).unwrap().get::<u32>(row),
+            7
+        );
+        assert_eq!(world.locations.get(&id), Some(&(target, row)));
+    }
+
+    #[test]
+    fn add_component_edge_is_memoized_across_entities() {
+        let mut world = World::new();
+        let base = world.new_archetype::<VectorOnly>();
+        let first = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 0.0, y: 0.0 },
+            },
+        );
+        let second = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 3.0, y: 4.0 },
+            },
+        );
+
+        let (first_target, _) = world.add_component::<Player>(first, &Player { id: 1 }).unwrap();
+        let (second_target, row) = world
+            .add_component::<Player>(second, &Player { id: 2 })
+            .unwrap();
+
+        assert_eq!(first_target, second_target);
+        assert_eq!(
+            world.archetypes[second_target]
+                .get::<Vector2>(0)
+                .unwrap()
+                .get::<f32>(row),
+            3.0
+        );
+        assert_eq!(world.locations.get(&second), Some(&(second_target, row)));
+    }
+
+    #[test]
+    fn remove_component_moves_entity_back_to_base_archetype() {
+        let mut world = World::new();
+        let base = world.new_archetype::<VectorOnly>();
+        let id = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 5.0, y: 6.0 },
+            },
+        );
+        world.add_component::<Player>(id, &Player { id: 42 }).unwrap();
+
+        let (back, row) = world.remove_component::<Player>(id).unwrap();
+
+        assert_eq!(back, base);
+        assert_eq!(
+            world.archetypes[back].get::<Vector2>(0).unwrap().get::<f32>(row),
+            5.0
+        );
+        assert!(!world.archetypes[back].has::<Player>());
+        assert_eq!(world.locations.get(&id), Some(&(back, row)));
+    }
+
+    #[test]
+    fn add_component_then_despawn_updates_tracked_location() {
+        // Regression test: `add_component`/`remove_component` used to take a
+        // raw `(archetype, row)` pair and never touch `World::entities`/
+        // `World::locations`, so a later `despawn` would swap-remove the
+        // wrong row (or panic) because the tracked location was stale.
+        let mut world = World::new();
+        let base = world.new_archetype::<VectorOnly>();
+        let first = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 1.0, y: 1.0 },
+            },
+        );
+        let second = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 2.0, y: 2.0 },
+            },
+        );
+
+        let (target, row) = world.add_component::<Player>(first, &Player { id: 9 }).unwrap();
+        assert_eq!(world.locations.get(&first), Some(&(target, row)));
+
+        world.despawn(first).unwrap();
+        assert_eq!(world.entities[target].len(), 0);
+
+        // `second` never moved, so despawning it must still work against the
+        // base archetype rather than panicking on a stale `target` location.
+        world.despawn(second).unwrap();
+        assert_eq!(world.archetypes[base].entity_count, 0);
+    }
+
+    #[test]
+    fn despawn_rewrites_moved_entitys_location() {
+        let mut world = World::new();
+        let base = world.new_archetype::<VectorOnly>();
+        let first = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 1.0, y: 1.0 },
+            },
+        );
+        let second = world.spawn(
+            base,
+            VectorOnly {
+                vector2: Vector2 { x: 2.0, y: 2.0 },
+            },
+        );
+
+        world.despawn(first).unwrap();
+
+        assert_eq!(world.archetypes[base].entity_count, 1);
+        assert_eq!(world.locations.get(&second), Some(&(base, 0)));
+        assert_eq!(
+            world.archetypes[base].get::<Vector2>(0).unwrap().get::<f32>(0),
+            2.0
+        );
+        assert_eq!(
+            world.despawn(first).unwrap_err(),
+            WorldError::EntityNotFound
+        );
+    }
+}